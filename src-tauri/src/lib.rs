@@ -2,30 +2,127 @@
 // This module handles the lifecycle of the Python sidecar subprocess
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
 use std::thread;
+use std::time::{Duration, Instant};
 use tauri::{AppHandle, Emitter, Manager, State};
 use tauri_plugin_shell::process::CommandChild;
 use tauri_plugin_shell::ShellExt;
-use std::path::PathBuf;
+use tokio::sync::oneshot;
 
-/// Application state holding the Python subprocess handle
-pub struct AppState {
-    /// Handle to the Python subprocess for sending commands
+/// Name of the built-in analysis engine, started automatically on app launch.
+const DEFAULT_SIDECAR: &str = "bio-engine";
+/// Default time to wait for a correlated reply before giving up.
+const CALL_TIMEOUT_MS: u64 = 10_000;
+/// How often the supervisor probes a sidecar with a heartbeat.
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(5);
+/// How long to wait for a heartbeat reply before counting it as missed.
+const HEARTBEAT_TIMEOUT: Duration = Duration::from_secs(3);
+/// Consecutive missed heartbeats before the supervisor restarts a sidecar.
+const MAX_MISSED_HEARTBEATS: u32 = 3;
+/// Initial delay before the first auto-restart attempt.
+const INITIAL_RESTART_BACKOFF: Duration = Duration::from_millis(500);
+/// Ceiling on the exponential restart backoff.
+const MAX_RESTART_BACKOFF: Duration = Duration::from_secs(30);
+/// Restart attempts allowed within `RESTART_WINDOW` before the supervisor gives up.
+const MAX_RESTART_ATTEMPTS: usize = 5;
+/// Rolling window used to cap restart attempts and avoid crash loops.
+const RESTART_WINDOW: Duration = Duration::from_secs(60);
+/// Default time to let a sidecar exit on its own before escalating to a hard kill.
+const DEFAULT_SHUTDOWN_GRACE_PERIOD: Duration = Duration::from_secs(3);
+/// Ceiling on a caller-supplied grace period, so a careless `grace_period_ms` can't tie
+/// up a restart indefinitely.
+const MAX_SHUTDOWN_GRACE_PERIOD: Duration = Duration::from_secs(60);
+/// How often to poll for process exit during a graceful shutdown.
+const SHUTDOWN_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Program, arguments, and environment needed to launch an additional named sidecar.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SidecarSpec {
+    pub program: String,
+    #[serde(default)]
+    pub args: Vec<String>,
+    #[serde(default)]
+    pub env: HashMap<String, String>,
+}
+
+/// Everything needed to own, drive, and supervise one named sidecar process.
+///
+/// Cloning a handle shares the same underlying process state (all fields are `Arc`s),
+/// which lets the reader thread and supervisor task outlive the `AppState` lock guard
+/// that produced them.
+#[derive(Clone)]
+struct SidecarHandle {
+    /// Handle to the subprocess for sending commands
     child: Arc<Mutex<Option<CommandChild>>>,
-    /// Flag indicating if the sidecar is running
+    /// Flag indicating if this sidecar is running
     is_running: Arc<Mutex<bool>>,
+    /// Outstanding `call_command` requests awaiting a correlated reply, keyed by request id
+    pending_calls: Arc<Mutex<HashMap<u64, oneshot::Sender<Result<String, String>>>>>,
+    /// Monotonically increasing id used to correlate requests with replies
+    next_call_id: Arc<AtomicU64>,
+    /// Set while a kill was intentional (restart/cleanup) so the supervisor doesn't
+    /// mistake the resulting `Terminated` event for a crash
+    manually_killed: Arc<AtomicBool>,
+    /// Ensures only one supervisor task is ever spawned across restarts of this sidecar
+    supervisor_started: Arc<AtomicBool>,
+    /// Bumped by `start_sidecar` every time a new process is spawned. A reader thread
+    /// captures the generation of the process it was spawned for; if a delayed
+    /// `Terminated` event for a killed process arrives after a newer generation has
+    /// already started, the thread recognizes it's stale and skips mutating
+    /// `is_running`/`pending_calls`, which by then belong to the new process.
+    generation: Arc<AtomicU64>,
+    /// How to relaunch this sidecar; `None` for the default engine's built-in resolution
+    spec: Arc<Mutex<Option<SidecarSpec>>>,
+    /// Ensures only one dev-mode Python source watcher is ever spawned for this sidecar
+    #[cfg(debug_assertions)]
+    watcher_started: Arc<AtomicBool>,
 }
 
-impl AppState {
-    pub fn new() -> Self {
+impl SidecarHandle {
+    fn new() -> Self {
         Self {
             child: Arc::new(Mutex::new(None)),
             is_running: Arc::new(Mutex::new(false)),
+            pending_calls: Arc::new(Mutex::new(HashMap::new())),
+            next_call_id: Arc::new(AtomicU64::new(1)),
+            manually_killed: Arc::new(AtomicBool::new(false)),
+            supervisor_started: Arc::new(AtomicBool::new(false)),
+            generation: Arc::new(AtomicU64::new(0)),
+            spec: Arc::new(Mutex::new(None)),
+            #[cfg(debug_assertions)]
+            watcher_started: Arc::new(AtomicBool::new(false)),
         }
     }
 }
 
+/// Application state holding the registry of Python subprocesses
+pub struct AppState {
+    /// Running (or previously-running) sidecars, keyed by name
+    sidecars: Arc<Mutex<HashMap<String, SidecarHandle>>>,
+}
+
+impl AppState {
+    pub fn new() -> Self {
+        Self {
+            sidecars: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Look up a sidecar's handle, registering a fresh (not-yet-spawned) one if this is
+    /// the first time `name` has been seen.
+    fn handle_for(&self, name: &str) -> Result<SidecarHandle, String> {
+        let mut sidecars = self.sidecars.lock().map_err(|e| e.to_string())?;
+        Ok(sidecars
+            .entry(name.to_string())
+            .or_insert_with(SidecarHandle::new)
+            .clone())
+    }
+}
+
 impl Default for AppState {
     fn default() -> Self {
         Self::new()
@@ -39,10 +136,23 @@ pub struct SidecarEvent {
     pub data: String,
 }
 
-/// Send a command to the Python sidecar via stdin
+/// A sidecar lifecycle or output event, tagged with which sidecar it came from so the
+/// frontend can demultiplex when more than one engine is running.
+#[derive(Debug, Serialize, Clone)]
+struct NamedSidecarEvent {
+    name: String,
+    data: String,
+}
+
+/// Send a command to a named sidecar via stdin
 #[tauri::command]
-async fn send_command(state: State<'_, AppState>, payload: String) -> Result<String, String> {
-    let mut child_guard = state.child.lock().map_err(|e| e.to_string())?;
+async fn send_command(
+    state: State<'_, AppState>,
+    name: String,
+    payload: String,
+) -> Result<String, String> {
+    let handle = state.handle_for(&name)?;
+    let mut child_guard = handle.child.lock().map_err(|e| e.to_string())?;
 
     if let Some(ref mut child) = *child_guard {
         // Ensure payload ends with newline for line-based protocol
@@ -54,45 +164,340 @@ async fn send_command(state: State<'_, AppState>, payload: String) -> Result<Str
         // Write to the child's stdin
         child
             .write(cmd.as_bytes())
-            .map_err(|e| format!("Failed to write to sidecar: {}", e))?;
+            .map_err(|e| format!("Failed to write to sidecar '{}': {}", name, e))?;
 
         Ok("Command sent".to_string())
     } else {
-        Err("Sidecar not running".to_string())
+        Err(format!("Sidecar '{}' not running", name))
     }
 }
 
-/// Check if the sidecar is running
+/// Send a command to a named sidecar and await the correlated reply.
+///
+/// Unlike `send_command`, this injects an `"id"` field into the outgoing payload and
+/// waits for a stdout line carrying a matching `"id"` back from the sidecar, so callers
+/// don't have to guess which `sidecar-output` event belongs to their request.
 #[tauri::command]
-fn is_sidecar_running(state: State<'_, AppState>) -> bool {
-    *state.is_running.lock().unwrap_or_else(|e| e.into_inner())
+async fn call_command(
+    state: State<'_, AppState>,
+    name: String,
+    payload: String,
+    timeout_ms: Option<u64>,
+) -> Result<String, String> {
+    let handle = state.handle_for(&name)?;
+    let value: serde_json::Value =
+        serde_json::from_str(&payload).map_err(|e| format!("Invalid JSON payload: {}", e))?;
+    send_and_await(
+        &handle,
+        value,
+        Duration::from_millis(timeout_ms.unwrap_or(CALL_TIMEOUT_MS)),
+    )
+    .await
 }
 
-/// Send a heartbeat to check sidecar health
+/// Inject a correlation id into `value`, write it to the sidecar's stdin, and await the
+/// matching reply (or a timeout/termination error). Shared by `call_command` and the
+/// supervisor's heartbeat probe.
+async fn send_and_await(
+    handle: &SidecarHandle,
+    mut value: serde_json::Value,
+    timeout: Duration,
+) -> Result<String, String> {
+    let obj = value
+        .as_object_mut()
+        .ok_or_else(|| "Payload must be a JSON object".to_string())?;
+
+    // Wraps around after u64::MAX requests; pending_calls is keyed by id so a stale
+    // entry would only collide if ~2^64 calls were outstanding at once, which can't happen.
+    let id = handle.next_call_id.fetch_add(1, Ordering::SeqCst);
+    obj.insert("id".to_string(), serde_json::json!(id));
+
+    let (tx, rx) = oneshot::channel();
+    handle
+        .pending_calls
+        .lock()
+        .map_err(|e| e.to_string())?
+        .insert(id, tx);
+
+    let mut line = value.to_string();
+    line.push('\n');
+
+    {
+        let mut child_guard = handle.child.lock().map_err(|e| e.to_string())?;
+        let write_result = match *child_guard {
+            Some(ref mut child) => child
+                .write(line.as_bytes())
+                .map_err(|e| format!("Failed to write to sidecar: {}", e)),
+            None => Err("Sidecar not running".to_string()),
+        };
+        if let Err(e) = write_result {
+            // The write never went out, so no reply will ever arrive for this id - drop
+            // the entry here rather than leaking it until the sidecar fully terminates.
+            handle.pending_calls.lock().map_err(|e| e.to_string())?.remove(&id);
+            return Err(e);
+        }
+    }
+
+    match tokio::time::timeout(timeout, rx).await {
+        Ok(Ok(Ok(response))) => Ok(response),
+        Ok(Ok(Err(e))) => Err(e),
+        Ok(Err(_)) => Err(format!("Sidecar closed before responding to request {}", id)),
+        Err(_) => {
+            // Timed out: drop the stale entry so a late reply completes no caller; it is
+            // logged and then forwarded like any other stdout line.
+            handle.pending_calls.lock().map_err(|e| e.to_string())?.remove(&id);
+            Err(format!("Timed out waiting for response to request {}", id))
+        }
+    }
+}
+
+/// Handle one complete stdout line from a sidecar: route it to a waiting `call_command`
+/// if its `"id"` matches, otherwise parse it as a typed `SidecarEvent` and emit it on a
+/// per-type `sidecar://<event_type>` channel, falling back to the untyped `sidecar-output`
+/// channel for anything that isn't a recognizable event (preserving pre-typed behavior).
+fn route_stdout_line(
+    app_handle: &AppHandle,
+    name: &str,
+    pending_calls: &Arc<Mutex<HashMap<u64, oneshot::Sender<Result<String, String>>>>>,
+    line: String,
+) {
+    if try_route_reply(pending_calls, &line) {
+        return;
+    }
+
+    match parse_typed_event(&line) {
+        Some(event) => {
+            let topic = format!("sidecar://{}", event.event_type);
+            if let Err(e) = app_handle.emit(
+                &topic,
+                NamedSidecarEvent {
+                    name: name.to_string(),
+                    data: event.data,
+                },
+            ) {
+                eprintln!("[BioViz] Failed to emit {}: {}", topic, e);
+            }
+        }
+        None => {
+            if let Err(e) = app_handle.emit(
+                "sidecar-output",
+                NamedSidecarEvent {
+                    name: name.to_string(),
+                    data: line,
+                },
+            ) {
+                eprintln!("[BioViz] Failed to emit event: {}", e);
+            }
+        }
+    }
+}
+
+/// Try to deliver `line` to a pending `call_command`/heartbeat request: parses it as JSON,
+/// looks for a matching `"id"` in `pending_calls`, and completes that request's oneshot if
+/// found. Returns `true` if the line was consumed this way (the caller should not also
+/// treat it as a typed event or raw output), `false` otherwise - including when the id is
+/// unknown or stale, which is logged and completes no request; such a line is then routed
+/// by the caller as a typed event or on `sidecar-output`, not dropped.
+fn try_route_reply(
+    pending_calls: &Arc<Mutex<HashMap<u64, oneshot::Sender<Result<String, String>>>>>,
+    line: &str,
+) -> bool {
+    let reply_id = serde_json::from_str::<serde_json::Value>(line)
+        .ok()
+        .and_then(|v| v.get("id").and_then(|id| id.as_u64()));
+
+    let Some(id) = reply_id else {
+        return false;
+    };
+
+    if let Ok(mut pending) = pending_calls.lock() {
+        if let Some(tx) = pending.remove(&id) {
+            let _ = tx.send(Ok(line.to_string()));
+            return true;
+        }
+        println!(
+            "[BioViz] No pending request for reply id {}, forwarding as ordinary output",
+            id
+        );
+    }
+    false
+}
+
+/// Parse a stdout line as a typed sidecar event: a JSON object carrying at least a
+/// `type` or `event_type` field. The optional `data` field becomes the event payload
+/// (stringified if it isn't already a string); otherwise the whole line is used.
+fn parse_typed_event(line: &str) -> Option<SidecarEvent> {
+    let value: serde_json::Value = serde_json::from_str(line).ok()?;
+    let obj = value.as_object()?;
+    let event_type = obj
+        .get("type")
+        .or_else(|| obj.get("event_type"))?
+        .as_str()?
+        .to_string();
+    let data = match obj.get("data") {
+        Some(serde_json::Value::String(s)) => s.clone(),
+        Some(other) => other.to_string(),
+        None => line.to_string(),
+    };
+    Some(SidecarEvent { event_type, data })
+}
+
+/// Check if a named sidecar is running
 #[tauri::command]
-async fn heartbeat(state: State<'_, AppState>) -> Result<String, String> {
-    send_command(state, r#"{"cmd": "HEARTBEAT"}"#.to_string()).await
+fn is_sidecar_running(state: State<'_, AppState>, name: String) -> bool {
+    match state.handle_for(&name) {
+        Ok(handle) => *handle.is_running.lock().unwrap_or_else(|e| e.into_inner()),
+        Err(_) => false,
+    }
 }
 
-/// Restart the sidecar if it's not running
+/// Send a heartbeat to check a sidecar's health
+#[tauri::command]
+async fn heartbeat(state: State<'_, AppState>, name: String) -> Result<String, String> {
+    send_command(state, name, r#"{"cmd": "HEARTBEAT"}"#.to_string()).await
+}
+
+/// Restart a named sidecar, giving it `grace_period_ms` (default 3s) to shut down
+/// cleanly before it is forcibly killed.
 #[tauri::command]
 async fn restart_sidecar(
     app_handle: AppHandle,
     state: State<'_, AppState>,
+    name: String,
+    grace_period_ms: Option<u64>,
 ) -> Result<String, String> {
-    // Kill existing process if any
-    {
-        let mut child_guard = state.child.lock().map_err(|e| e.to_string())?;
-        if let Some(child) = child_guard.take() {
-            let _ = child.kill();
-        }
+    let handle = state.handle_for(&name)?;
+    let grace_period = grace_period_ms
+        .map(Duration::from_millis)
+        .unwrap_or(DEFAULT_SHUTDOWN_GRACE_PERIOD)
+        .min(MAX_SHUTDOWN_GRACE_PERIOD);
+    restart_sidecar_internal(&app_handle, &handle, &name, grace_period).await
+}
+
+/// Register and spawn an additional named sidecar at runtime (e.g. a GSEA worker
+/// alongside the default plotting engine).
+///
+/// Errors if `name` already has a running child rather than silently overwriting
+/// `handle.child`, which would orphan the previous process with no way to kill it;
+/// callers that want to relaunch an existing sidecar should use `restart_sidecar`.
+#[tauri::command]
+async fn spawn_named_sidecar(
+    app_handle: AppHandle,
+    state: State<'_, AppState>,
+    name: String,
+    spec: SidecarSpec,
+) -> Result<(), String> {
+    let handle = state.handle_for(&name)?;
+    if *handle.is_running.lock().map_err(|e| e.to_string())? {
+        return Err(format!(
+            "Sidecar '{}' is already running; use restart_sidecar to relaunch it",
+            name
+        ));
     }
+    start_sidecar(&app_handle, &handle, &name, Some(spec))
+}
+
+/// Kill and respawn a sidecar, reusing whatever spec it was last launched with. Shared
+/// by the `restart_sidecar` command and the supervisor's auto-restart path.
+///
+/// Marks `manually_killed` for the duration of the kill so the reader thread's
+/// `Terminated` event isn't mistaken for an unexpected crash by the supervisor.
+///
+/// Async because `shutdown_sidecar` waits out the grace period on a timer rather than a
+/// blocking sleep; callers that aren't already on the async runtime (the dev-mode file
+/// watcher's dedicated thread) drive this with `tauri::async_runtime::block_on`.
+async fn restart_sidecar_internal(
+    app_handle: &AppHandle,
+    handle: &SidecarHandle,
+    name: &str,
+    grace_period: Duration,
+) -> Result<String, String> {
+    handle.manually_killed.store(true, Ordering::SeqCst);
 
-    // Spawn new sidecar
-    spawn_sidecar(&app_handle, &state)?;
+    shutdown_sidecar(app_handle, handle, name, grace_period).await?;
+
+    // Spawn new process using whatever spec (or default resolution) it was started with
+    let result = start_sidecar(app_handle, handle, name, None);
+    handle.manually_killed.store(false, Ordering::SeqCst);
+    result?;
     Ok("Sidecar restarted".to_string())
 }
 
+/// Ask a sidecar to shut down, escalating to a hard kill if it doesn't exit in time.
+///
+/// First writes a `{"cmd": "SHUTDOWN"}` line and (on Unix) sends `SIGTERM`, then polls
+/// for the reader thread to observe process exit; only once `grace_period` elapses does
+/// it fall back to `CommandChild::kill()`. Emits `sidecar-shutdown` reporting whether the
+/// exit was graceful or forced.
+///
+/// Waits on `tokio::time::sleep` rather than `thread::sleep` so awaiting this from a
+/// tokio task (the `restart_sidecar` command, the supervisor) never pins a worker thread
+/// for the whole grace period.
+async fn shutdown_sidecar(
+    app_handle: &AppHandle,
+    handle: &SidecarHandle,
+    name: &str,
+    grace_period: Duration,
+) -> Result<(), String> {
+    let pid = {
+        let mut child_guard = handle.child.lock().map_err(|e| e.to_string())?;
+        match *child_guard {
+            Some(ref mut child) => {
+                let pid = child.pid();
+                let _ = child.write(b"{\"cmd\": \"SHUTDOWN\"}\n");
+                Some(pid)
+            }
+            None => None,
+        }
+    };
+
+    if pid.is_none() {
+        // Nothing running, so there's nothing to shut down
+        return Ok(());
+    }
+
+    #[cfg(unix)]
+    if let Some(pid) = pid {
+        unsafe {
+            libc::kill(pid as libc::pid_t, libc::SIGTERM);
+        }
+    }
+
+    let deadline = Instant::now() + grace_period;
+    let exited_gracefully = loop {
+        let running = *handle.is_running.lock().unwrap_or_else(|e| e.into_inner());
+        if !running {
+            break true;
+        }
+        if Instant::now() >= deadline {
+            break false;
+        }
+        tokio::time::sleep(SHUTDOWN_POLL_INTERVAL).await;
+    };
+
+    if !exited_gracefully {
+        println!(
+            "[BioViz] Sidecar '{}' did not exit within {:?}, forcing shutdown",
+            name, grace_period
+        );
+        if let Ok(mut child_guard) = handle.child.lock() {
+            if let Some(child) = child_guard.take() {
+                let _ = child.kill();
+            }
+        }
+    }
+
+    let _ = app_handle.emit(
+        "sidecar-shutdown",
+        NamedSidecarEvent {
+            name: name.to_string(),
+            data: if exited_gracefully { "graceful" } else { "forced" }.to_string(),
+        },
+    );
+
+    Ok(())
+}
+
 /// Open or toggle developer tools
 #[tauri::command]
 fn open_devtools(window: tauri::Window) {
@@ -105,7 +510,7 @@ fn open_devtools(window: tauri::Window) {
             eprintln!("[BioViz] Could not find main webview window for devtools");
         }
     }
-    
+
     #[cfg(not(feature = "devtools"))]
     {
         let _ = window;
@@ -124,9 +529,10 @@ pub fn run() {
         .setup(|app| {
             let app_handle = app.handle().clone();
             let state = app.state::<AppState>();
+            let handle = state.handle_for(DEFAULT_SIDECAR)?;
 
-            // Spawn the Python sidecar
-            match spawn_sidecar(&app_handle, &state) {
+            // Spawn the default Python sidecar
+            match start_sidecar(&app_handle, &handle, DEFAULT_SIDECAR, None) {
                 Ok(_) => {
                     println!("[BioViz] Engine sidecar started successfully");
                 }
@@ -143,135 +549,167 @@ pub fn run() {
             if let tauri::WindowEvent::CloseRequested { .. } = event {
                 // No action needed
             }
-            
+
             // Clean up on window close or destroy
             if let tauri::WindowEvent::Destroyed = event {
-                cleanup_sidecar(window.state::<AppState>());
+                let app_handle = window.app_handle().clone();
+                cleanup_sidecar(&app_handle, window.state::<AppState>());
             }
         })
         .invoke_handler(tauri::generate_handler![
             send_command,
+            call_command,
             is_sidecar_running,
             heartbeat,
             restart_sidecar,
+            spawn_named_sidecar,
             open_devtools  // Add new command
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
 
-/// Clean up the sidecar process
-fn cleanup_sidecar(state: State<'_, AppState>) {
-    println!("[BioViz] Cleaning up sidecar process...");
+/// Clean up every registered sidecar process
+fn cleanup_sidecar(app_handle: &AppHandle, state: State<'_, AppState>) {
+    println!("[BioViz] Cleaning up sidecar processes...");
 
-    // Mark as not running
-    if let Ok(mut is_running) = state.is_running.lock() {
-        *is_running = false;
-    }
+    let sidecars = match state.sidecars.lock() {
+        Ok(sidecars) => sidecars.clone(),
+        Err(e) => {
+            eprintln!("[BioViz] Failed to lock sidecar registry during cleanup: {}", e);
+            return;
+        }
+    };
+
+    for (name, handle) in sidecars.iter() {
+        // This kill is intentional, so the supervisor shouldn't treat it as a crash
+        handle.manually_killed.store(true, Ordering::SeqCst);
 
-    // Kill the child process
-    if let Ok(mut child_guard) = state.child.lock() {
-        if let Some(child) = child_guard.take() {
-            match child.kill() {
-                Ok(_) => println!("[BioViz] Sidecar process killed successfully"),
-                Err(e) => eprintln!("[BioViz] Failed to kill sidecar: {}", e),
+        // `cleanup_sidecar` runs on the window-event callback, not a tokio task, so
+        // blocking here to drive the async shutdown is fine - it only happens once, on
+        // app teardown.
+        let shutdown = tauri::async_runtime::block_on(shutdown_sidecar(
+            app_handle,
+            handle,
+            name,
+            DEFAULT_SHUTDOWN_GRACE_PERIOD,
+        ));
+        if let Err(e) = shutdown {
+            eprintln!("[BioViz] Failed to shut down sidecar '{}': {}", name, e);
+        }
+
+        if let Ok(mut is_running) = handle.is_running.lock() {
+            *is_running = false;
+        }
+
+        // Fail any in-flight call_command requests rather than leaving them pending forever
+        if let Ok(mut pending) = handle.pending_calls.lock() {
+            for (_, tx) in pending.drain() {
+                let _ = tx.send(Err("Sidecar cleaned up".to_string()));
             }
         }
     }
 }
 
-/// Spawn the Python sidecar process using Tauri's shell plugin
-fn spawn_sidecar(app_handle: &AppHandle, state: &State<'_, AppState>) -> Result<(), String> {
-    // In dev builds, prefer running the Python source directly so backend edits take effect
-    // without rebuilding the PyInstaller sidecar binary.
-    #[cfg(debug_assertions)]
-    let sidecar_command = {
-        let repo_root: PathBuf = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
-            .parent()
-            .unwrap_or_else(|| std::path::Path::new("."))
-            .to_path_buf();
-        let script = repo_root.join("python").join("bio_engine.py");
+/// Spawn a named sidecar process using Tauri's shell plugin.
+///
+/// `spec` selects how the process is launched: `Some(spec)` runs an arbitrary
+/// program/args/env (used for additional engines registered via
+/// `spawn_named_sidecar`, and remembered for later restarts); `None` reuses whatever
+/// spec this sidecar last ran with, or - if there is none yet and `name` is the default
+/// engine - falls back to the built-in dev/release resolution for `bio_engine.py`.
+fn start_sidecar(
+    app_handle: &AppHandle,
+    handle: &SidecarHandle,
+    name: &str,
+    spec: Option<SidecarSpec>,
+) -> Result<(), String> {
+    let _ = app_handle.emit(
+        "sidecar-state",
+        NamedSidecarEvent {
+            name: name.to_string(),
+            data: "starting".to_string(),
+        },
+    );
 
-        if script.exists() {
-            // Try miniconda Python first (has gseapy installed), fallback to system python3
-            let python = if cfg!(target_os = "windows") { 
-                "python".to_string() 
-            } else {
-                // Check if miniconda Python exists with required packages
-                let miniconda_python = std::path::Path::new("/Users/haifeng/miniconda3/bin/python3");
-                if miniconda_python.exists() {
-                    miniconda_python.to_string_lossy().to_string()
-                } else {
-                    "python3".to_string()
-                }
-            };
-            println!(
-                "[BioViz] Dev mode: spawning Python engine from source: {}",
-                script.display()
-            );
-            
-            // Pass AI configuration environment variables to Python sidecar
-            let mut cmd = app_handle
-                .shell()
-                .command(python)
-                .args([script.to_string_lossy().to_string()])
-                .env("BIOVIZ_USE_SOURCE", "1");
-            
-            // Pass AI provider configuration
-            if let Ok(provider) = std::env::var("AI_PROVIDER") {
-                cmd = cmd.env("AI_PROVIDER", provider);
-            }
-            if let Ok(key) = std::env::var("DASHSCOPE_API_KEY") {
-                cmd = cmd.env("DASHSCOPE_API_KEY", key);
-            }
-            if let Ok(key) = std::env::var("DEEPSEEK_API_KEY") {
-                cmd = cmd.env("DEEPSEEK_API_KEY", key);
-            }
-            if let Ok(model) = std::env::var("DEEPSEEK_MODEL") {
-                cmd = cmd.env("DEEPSEEK_MODEL", model);
-            }
-            
-            cmd
-        } else {
-            app_handle
-                .shell()
-                .sidecar("bio-engine")
-                .map_err(|e| format!("Failed to create sidecar command: {}", e))?
+    if spec.is_some() {
+        *handle.spec.lock().map_err(|e| e.to_string())? = spec.clone();
+    }
+    let remembered_spec = handle.spec.lock().map_err(|e| e.to_string())?.clone();
+
+    let sidecar_command = if let Some(spec) = remembered_spec {
+        let mut cmd = app_handle.shell().command(spec.program).args(spec.args);
+        for (key, value) in spec.env {
+            cmd = cmd.env(key, value);
         }
+        cmd
+    } else {
+        default_engine_command(app_handle, name)?
     };
 
-    #[cfg(not(debug_assertions))]
-    let sidecar_command = app_handle
-        .shell()
-        .sidecar("bio-engine")
-        .map_err(|e| format!("Failed to create sidecar command: {}", e))?;
-
     // Spawn the process
     let (mut rx, child) = sidecar_command
         .spawn()
-        .map_err(|e| format!("Failed to spawn sidecar: {}", e))?;
+        .map_err(|e| format!("Failed to spawn sidecar '{}': {}", name, e))?;
+
+    // Claim a fresh generation for this process now that it's actually running, so the
+    // reader thread we're about to spawn can tell a delayed `Terminated` event for an
+    // earlier, already-replaced process apart from one for itself.
+    let my_generation = handle.generation.fetch_add(1, Ordering::SeqCst) + 1;
 
     // Store the child handle for writing
     {
-        let mut child_guard = state.child.lock().map_err(|e| e.to_string())?;
+        let mut child_guard = handle.child.lock().map_err(|e| e.to_string())?;
         *child_guard = Some(child);
     }
 
     // Mark as running
     {
-        let mut is_running = state.is_running.lock().map_err(|e| e.to_string())?;
+        let mut is_running = handle.is_running.lock().map_err(|e| e.to_string())?;
         *is_running = true;
     }
 
+    // Start this sidecar's supervisor exactly once; it outlives individual restarts and
+    // keeps probing whichever child is currently installed in `handle.child`.
+    if handle
+        .supervisor_started
+        .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+        .is_ok()
+    {
+        tauri::async_runtime::spawn(run_supervisor(
+            app_handle.clone(),
+            name.to_string(),
+            handle.clone(),
+        ));
+    }
+
+    // In dev builds, watch the default engine's Python source and auto-restart on edit
+    #[cfg(debug_assertions)]
+    if name == DEFAULT_SIDECAR
+        && handle
+            .watcher_started
+            .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+            .is_ok()
+    {
+        start_dev_watcher(app_handle.clone(), handle.clone(), name.to_string());
+    }
+
     // Clone for the reader thread
     let app_handle_clone = app_handle.clone();
-    let is_running_clone = state.is_running.clone();
+    let name_clone = name.to_string();
+    let is_running_clone = handle.is_running.clone();
+    let pending_calls_clone = handle.pending_calls.clone();
+    let generation_clone = handle.generation.clone();
 
     // Spawn a thread to read stdout and emit events to frontend
     thread::spawn(move || {
         use tauri_plugin_shell::process::CommandEvent;
 
-        println!("[BioViz] Sidecar reader thread started");
+        println!("[BioViz] Sidecar '{}' reader thread started", name_clone);
+
+        // A JSON message can span multiple stdout chunks (or several can share one),
+        // so accumulate raw bytes here and only process complete, newline-terminated lines.
+        let mut stdout_buffer: Vec<u8> = Vec::new();
 
         // Block on receiving events from the sidecar
         while let Some(event) = rx.blocking_recv() {
@@ -283,42 +721,476 @@ fn spawn_sidecar(app_handle: &AppHandle, state: &State<'_, AppState>) -> Result<
             }
 
             match event {
-                CommandEvent::Stdout(line) => {
-                    let output = String::from_utf8_lossy(&line).trim().to_string();
-                    if !output.is_empty() {
-                        println!("[BioViz] Sidecar stdout: {}", output);
-                        // Emit to frontend
-                        if let Err(e) = app_handle_clone.emit("sidecar-output", &output) {
-                            eprintln!("[BioViz] Failed to emit event: {}", e);
+                CommandEvent::Stdout(chunk) => {
+                    stdout_buffer.extend_from_slice(&chunk);
+
+                    while let Some(newline_pos) = stdout_buffer.iter().position(|&b| b == b'\n') {
+                        let line_bytes: Vec<u8> = stdout_buffer.drain(..=newline_pos).collect();
+                        let line = String::from_utf8_lossy(&line_bytes).trim().to_string();
+                        if line.is_empty() {
+                            continue;
                         }
+
+                        println!("[BioViz] Sidecar '{}' stdout: {}", name_clone, line);
+                        route_stdout_line(
+                            &app_handle_clone,
+                            &name_clone,
+                            &pending_calls_clone,
+                            line,
+                        );
                     }
                 }
                 CommandEvent::Stderr(line) => {
                     let error = String::from_utf8_lossy(&line).trim().to_string();
                     if !error.is_empty() {
-                        eprintln!("[BioViz] Sidecar stderr: {}", error);
-                        if let Err(e) = app_handle_clone.emit("sidecar-error", &error) {
+                        eprintln!("[BioViz] Sidecar '{}' stderr: {}", name_clone, error);
+                        if let Err(e) = app_handle_clone.emit(
+                            "sidecar-error",
+                            NamedSidecarEvent {
+                                name: name_clone.clone(),
+                                data: error,
+                            },
+                        ) {
                             eprintln!("[BioViz] Failed to emit error event: {}", e);
                         }
                     }
                 }
                 CommandEvent::Error(error) => {
-                    eprintln!("[BioViz] Sidecar error: {}", error);
-                    if let Err(e) = app_handle_clone.emit("sidecar-error", &error) {
+                    eprintln!("[BioViz] Sidecar '{}' error: {}", name_clone, error);
+                    if let Err(e) = app_handle_clone.emit(
+                        "sidecar-error",
+                        NamedSidecarEvent {
+                            name: name_clone.clone(),
+                            data: error,
+                        },
+                    ) {
                         eprintln!("[BioViz] Failed to emit error event: {}", e);
                     }
                 }
                 CommandEvent::Terminated(status) => {
-                    println!("[BioViz] Sidecar terminated with status: {:?}", status);
-                    let _ = app_handle_clone.emit("sidecar-terminated", format!("{:?}", status));
+                    println!(
+                        "[BioViz] Sidecar '{}' terminated with status: {:?}",
+                        name_clone, status
+                    );
+                    let _ = app_handle_clone.emit(
+                        "sidecar-terminated",
+                        NamedSidecarEvent {
+                            name: name_clone.clone(),
+                            data: format!("{:?}", status),
+                        },
+                    );
+
+                    // A shutdown that gave up waiting and hard-killed this process may
+                    // already have let start_sidecar spawn a newer generation sharing
+                    // these same is_running/pending_calls Arcs by the time this delayed
+                    // event arrives. If so, this thread is stale: touching that state
+                    // would stomp on the new process's legitimate state instead of this
+                    // dead one's.
+                    if generation_clone.load(Ordering::SeqCst) == my_generation {
+                        // Let the supervisor notice the process is gone; it decides
+                        // whether this was intentional (manually_killed) or a crash to
+                        // recover from.
+                        if let Ok(mut running) = is_running_clone.lock() {
+                            *running = false;
+                        }
+
+                        // Fail every outstanding call_command so no caller hangs forever
+                        // waiting on a reply that will never arrive.
+                        if let Ok(mut pending) = pending_calls_clone.lock() {
+                            for (_, tx) in pending.drain() {
+                                let _ = tx.send(Err("Sidecar terminated".to_string()));
+                            }
+                        }
+                    } else {
+                        println!(
+                            "[BioViz] Sidecar '{}' reader thread for a superseded process observed termination; ignoring",
+                            name_clone
+                        );
+                    }
                     break;
                 }
                 _ => {}
             }
         }
 
-        println!("[BioViz] Sidecar reader thread exiting");
+        println!("[BioViz] Sidecar '{}' reader thread exiting", name_clone);
     });
 
     Ok(())
 }
+
+/// Resolve the command used to launch the built-in default engine: in dev builds, prefer
+/// running `python/bio_engine.py` directly so backend edits take effect without
+/// rebuilding the PyInstaller sidecar binary; otherwise (or if the source tree isn't
+/// present) fall back to the bundled `bio-engine` sidecar binary.
+///
+/// Only ever resolves `DEFAULT_SIDECAR`; any other name reaching this point has no
+/// remembered `SidecarSpec` (i.e. was never registered via `spawn_named_sidecar`), so it
+/// errors instead of silently spawning a second copy of the default engine under a bogus
+/// name.
+fn default_engine_command(
+    app_handle: &AppHandle,
+    name: &str,
+) -> Result<tauri_plugin_shell::process::Command, String> {
+    if name != DEFAULT_SIDECAR {
+        return Err(format!("no spec registered for sidecar '{}'", name));
+    }
+
+    #[cfg(debug_assertions)]
+    {
+        let script = dev_engine_script();
+
+        if script.exists() {
+            // Try miniconda Python first (has gseapy installed), fallback to system python3
+            let python = if cfg!(target_os = "windows") {
+                "python".to_string()
+            } else {
+                // Check if miniconda Python exists with required packages
+                let miniconda_python = std::path::Path::new("/Users/haifeng/miniconda3/bin/python3");
+                if miniconda_python.exists() {
+                    miniconda_python.to_string_lossy().to_string()
+                } else {
+                    "python3".to_string()
+                }
+            };
+            println!(
+                "[BioViz] Dev mode: spawning Python engine from source: {}",
+                script.display()
+            );
+
+            // Pass AI configuration environment variables to Python sidecar
+            let mut cmd = app_handle
+                .shell()
+                .command(python)
+                .args([script.to_string_lossy().to_string()])
+                .env("BIOVIZ_USE_SOURCE", "1");
+
+            // Pass AI provider configuration
+            if let Ok(provider) = std::env::var("AI_PROVIDER") {
+                cmd = cmd.env("AI_PROVIDER", provider);
+            }
+            if let Ok(key) = std::env::var("DASHSCOPE_API_KEY") {
+                cmd = cmd.env("DASHSCOPE_API_KEY", key);
+            }
+            if let Ok(key) = std::env::var("DEEPSEEK_API_KEY") {
+                cmd = cmd.env("DEEPSEEK_API_KEY", key);
+            }
+            if let Ok(model) = std::env::var("DEEPSEEK_MODEL") {
+                cmd = cmd.env("DEEPSEEK_MODEL", model);
+            }
+
+            return Ok(cmd);
+        }
+    }
+
+    app_handle
+        .shell()
+        .sidecar("bio-engine")
+        .map_err(|e| format!("Failed to create sidecar command: {}", e))
+}
+
+/// Path to the default engine's Python source, used both to launch it in dev builds and
+/// to pick the directory the dev-mode file watcher watches.
+#[cfg(debug_assertions)]
+fn dev_engine_script() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap_or_else(|| std::path::Path::new("."))
+        .to_path_buf()
+        .join("python")
+        .join("bio_engine.py")
+}
+
+/// Whether a filesystem event is a `.py` source edit worth restarting the sidecar for:
+/// a modify or create event touching a non-`__pycache__` path with a `.py` extension.
+#[cfg(debug_assertions)]
+fn is_relevant_py_change(event: &notify::Event) -> bool {
+    if !matches!(
+        event.kind,
+        notify::EventKind::Modify(_) | notify::EventKind::Create(_)
+    ) {
+        return false;
+    }
+    event.paths.iter().any(|p| {
+        p.extension().map(|ext| ext == "py").unwrap_or(false)
+            && !p.components().any(|c| c.as_os_str() == "__pycache__")
+    })
+}
+
+/// Watch the default engine's Python source tree for changes and restart it on edit, so
+/// dev builds pick up backend changes without a manual restart. Only runs in debug
+/// builds, and only once per sidecar (guarded by `watcher_started`).
+#[cfg(debug_assertions)]
+fn start_dev_watcher(app_handle: AppHandle, handle: SidecarHandle, name: String) {
+    use notify::{RecursiveMode, Watcher};
+    use std::sync::mpsc::channel;
+
+    let watch_dir = match dev_engine_script().parent() {
+        Some(dir) => dir.to_path_buf(),
+        None => return,
+    };
+    if !watch_dir.exists() {
+        return;
+    }
+
+    thread::spawn(move || {
+        let (tx, rx) = channel();
+        let mut watcher = match notify::recommended_watcher(tx) {
+            Ok(watcher) => watcher,
+            Err(e) => {
+                eprintln!("[BioViz] Dev watcher: failed to create watcher: {}", e);
+                return;
+            }
+        };
+        if let Err(e) = watcher.watch(&watch_dir, RecursiveMode::Recursive) {
+            eprintln!(
+                "[BioViz] Dev watcher: failed to watch {}: {}",
+                watch_dir.display(),
+                e
+            );
+            return;
+        }
+
+        println!(
+            "[BioViz] Dev watcher: watching {} for Python changes",
+            watch_dir.display()
+        );
+
+        let debounce = Duration::from_millis(300);
+        while let Ok(result) = rx.recv() {
+            match result {
+                Ok(event) if is_relevant_py_change(&event) => {}
+                Ok(_) => continue,
+                Err(e) => {
+                    eprintln!("[BioViz] Dev watcher: error: {}", e);
+                    continue;
+                }
+            }
+
+            // Debounce: any further event (relevant or not) within the window resets the
+            // wait, so one save - which often touches the filesystem more than once -
+            // triggers a single restart instead of one per event.
+            while rx.recv_timeout(debounce).is_ok() {}
+
+            println!(
+                "[BioViz] Dev watcher: Python source changed, restarting '{}'",
+                name
+            );
+            let _ = app_handle.emit(
+                "sidecar-reloading",
+                NamedSidecarEvent {
+                    name: name.clone(),
+                    data: watch_dir.display().to_string(),
+                },
+            );
+
+            // This thread is dedicated to the watcher, not a tokio worker, so blocking it
+            // on the async restart is fine.
+            let restart = tauri::async_runtime::block_on(restart_sidecar_internal(
+                &app_handle,
+                &handle,
+                &name,
+                DEFAULT_SHUTDOWN_GRACE_PERIOD,
+            ));
+            if let Err(e) = restart {
+                eprintln!("[BioViz] Dev watcher: failed to restart '{}': {}", name, e);
+            }
+        }
+
+        println!("[BioViz] Dev watcher: stopped watching {}", watch_dir.display());
+    });
+}
+
+/// Background task that keeps one named sidecar alive: periodically heartbeats it and,
+/// after too many misses or an unexpected exit, restarts it with exponential backoff.
+/// Runs for the lifetime of the app; started once from `start_sidecar` per sidecar name
+/// via `supervisor_started`.
+async fn run_supervisor(app_handle: AppHandle, name: String, handle: SidecarHandle) {
+    let mut consecutive_misses: u32 = 0;
+    let mut backoff = INITIAL_RESTART_BACKOFF;
+    let mut was_healthy = true;
+    let mut restart_attempts: Vec<Instant> = Vec::new();
+
+    loop {
+        tokio::time::sleep(HEARTBEAT_INTERVAL).await;
+
+        // A restart or shutdown already in progress knows what it's doing; don't pile on.
+        if handle.manually_killed.load(Ordering::SeqCst) {
+            continue;
+        }
+
+        let is_running = *handle.is_running.lock().unwrap_or_else(|e| e.into_inner());
+        let ok = is_running
+            && send_and_await(
+                &handle,
+                serde_json::json!({"cmd": "HEARTBEAT"}),
+                HEARTBEAT_TIMEOUT,
+            )
+            .await
+            .is_ok();
+
+        if ok {
+            consecutive_misses = 0;
+            backoff = INITIAL_RESTART_BACKOFF;
+            if !was_healthy {
+                was_healthy = true;
+                let _ = app_handle.emit(
+                    "sidecar-state",
+                    NamedSidecarEvent {
+                        name: name.clone(),
+                        data: "healthy".to_string(),
+                    },
+                );
+            }
+            continue;
+        }
+
+        // An unexpected exit (is_running already false) counts immediately; a hung
+        // process has to miss a few heartbeats in a row first.
+        consecutive_misses += 1;
+        if was_healthy {
+            was_healthy = false;
+            let _ = app_handle.emit(
+                "sidecar-state",
+                NamedSidecarEvent {
+                    name: name.clone(),
+                    data: "unhealthy".to_string(),
+                },
+            );
+        }
+        if is_running && consecutive_misses < MAX_MISSED_HEARTBEATS {
+            continue;
+        }
+
+        let now = Instant::now();
+        restart_attempts.retain(|t| now.duration_since(*t) < RESTART_WINDOW);
+        if restart_attempts.len() >= MAX_RESTART_ATTEMPTS {
+            eprintln!(
+                "[BioViz] Supervisor for '{}': too many restarts in the last {:?}, giving up",
+                name, RESTART_WINDOW
+            );
+            let _ = app_handle.emit(
+                "sidecar-state",
+                NamedSidecarEvent {
+                    name: name.clone(),
+                    data: "failed".to_string(),
+                },
+            );
+            continue;
+        }
+        restart_attempts.push(now);
+
+        let _ = app_handle.emit(
+            "sidecar-state",
+            NamedSidecarEvent {
+                name: name.clone(),
+                data: "restarting".to_string(),
+            },
+        );
+        tokio::time::sleep(backoff).await;
+        backoff = (backoff * 2).min(MAX_RESTART_BACKOFF);
+        consecutive_misses = 0;
+
+        match restart_sidecar_internal(&app_handle, &handle, &name, DEFAULT_SHUTDOWN_GRACE_PERIOD).await {
+            Ok(_) => was_healthy = true,
+            Err(e) => eprintln!("[BioViz] Supervisor for '{}': restart failed: {}", name, e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_typed_event_reads_type_and_string_data() {
+        let line = r#"{"type": "progress", "data": "50%"}"#;
+        let event = parse_typed_event(line).expect("should parse");
+        assert_eq!(event.event_type, "progress");
+        assert_eq!(event.data, "50%");
+    }
+
+    #[test]
+    fn parse_typed_event_falls_back_to_event_type_field() {
+        let line = r#"{"event_type": "log", "data": {"level": "info"}}"#;
+        let event = parse_typed_event(line).expect("should parse");
+        assert_eq!(event.event_type, "log");
+        assert_eq!(event.data, r#"{"level":"info"}"#);
+    }
+
+    #[test]
+    fn parse_typed_event_uses_whole_line_when_no_data_field() {
+        let line = r#"{"type": "plot"}"#;
+        let event = parse_typed_event(line).expect("should parse");
+        assert_eq!(event.event_type, "plot");
+        assert_eq!(event.data, line);
+    }
+
+    #[test]
+    fn parse_typed_event_returns_none_for_untyped_or_invalid_json() {
+        assert!(parse_typed_event("not json").is_none());
+        assert!(parse_typed_event(r#"{"result": 42}"#).is_none());
+    }
+
+    #[test]
+    fn try_route_reply_completes_matching_pending_call() {
+        let pending_calls: Arc<Mutex<HashMap<u64, oneshot::Sender<Result<String, String>>>>> =
+            Arc::new(Mutex::new(HashMap::new()));
+        let (tx, rx) = oneshot::channel();
+        pending_calls.lock().unwrap().insert(7, tx);
+
+        let line = r#"{"id": 7, "status": "ok"}"#;
+        assert!(try_route_reply(&pending_calls, line));
+        assert!(pending_calls.lock().unwrap().is_empty());
+
+        let received = rx.try_recv().expect("oneshot should have fired");
+        assert_eq!(received, Ok(line.to_string()));
+    }
+
+    #[test]
+    fn try_route_reply_ignores_stale_or_unknown_ids() {
+        let pending_calls: Arc<Mutex<HashMap<u64, oneshot::Sender<Result<String, String>>>>> =
+            Arc::new(Mutex::new(HashMap::new()));
+        assert!(!try_route_reply(&pending_calls, r#"{"id": 99}"#));
+    }
+
+    #[test]
+    fn try_route_reply_ignores_lines_without_an_id() {
+        let pending_calls: Arc<Mutex<HashMap<u64, oneshot::Sender<Result<String, String>>>>> =
+            Arc::new(Mutex::new(HashMap::new()));
+        assert!(!try_route_reply(
+            &pending_calls,
+            r#"{"type": "log", "data": "hi"}"#
+        ));
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    fn is_relevant_py_change_accepts_py_modify_and_create() {
+        use notify::event::{CreateKind, ModifyKind};
+        use notify::{Event, EventKind};
+
+        let modify =
+            Event::new(EventKind::Modify(ModifyKind::Any)).add_path(PathBuf::from("python/bio_engine.py"));
+        assert!(is_relevant_py_change(&modify));
+
+        let create = Event::new(EventKind::Create(CreateKind::Any))
+            .add_path(PathBuf::from("python/new_module.py"));
+        assert!(is_relevant_py_change(&create));
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    fn is_relevant_py_change_ignores_non_py_and_pycache() {
+        use notify::event::ModifyKind;
+        use notify::{Event, EventKind};
+
+        let non_py =
+            Event::new(EventKind::Modify(ModifyKind::Any)).add_path(PathBuf::from("python/notes.txt"));
+        assert!(!is_relevant_py_change(&non_py));
+
+        let pycache = Event::new(EventKind::Modify(ModifyKind::Any))
+            .add_path(PathBuf::from("python/__pycache__/bio_engine.cpython-311.pyc"));
+        assert!(!is_relevant_py_change(&pycache));
+    }
+}